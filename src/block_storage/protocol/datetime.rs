@@ -0,0 +1,229 @@
+// Copyright 2024 Sandro-Alessio Gierens <sandro@gierens.de>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared timestamp parsing and other serde helpers reused across
+//! block-storage resources.
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use serde::Serialize;
+use serde::{de, Deserialize, Deserializer};
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTime {
+    WithTz(chrono::DateTime<chrono::FixedOffset>),
+    WithoutTz(chrono::NaiveDateTime),
+}
+
+#[cfg(feature = "time")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTime {
+    WithTz(time::OffsetDateTime),
+    WithoutTz(time::PrimitiveDateTime),
+}
+
+/// A timestamp, parsed into [`DateTime`] when the `chrono` or `time` feature
+/// is enabled, or left as the raw wire string otherwise.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub type Timestamp = DateTime;
+
+/// A timestamp, parsed into [`DateTime`] when the `chrono` or `time` feature
+/// is enabled, or left as the raw wire string otherwise.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Timestamp = String;
+
+// chrono's `%.f` (used below by the chrono backend) treats the captured
+// fractional digit string as a literal nanosecond count rather than a
+// positional decimal fraction, e.g. ".123456" parses as 123456ns, not
+// 123456000ns. The `time` backend has to replicate that quirk by hand so
+// the two backends agree on the wall-clock value of the same wire string.
+#[cfg(feature = "time")]
+const NAIVE_DATETIME_PREFIX_FORMAT: &[time::format_description::FormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+
+#[cfg(feature = "time")]
+fn parse_naive_datetime(s: &str) -> Result<time::PrimitiveDateTime, String> {
+    let (prefix, frac) = match s.split_once('.') {
+        Some((prefix, frac)) => (prefix, Some(frac)),
+        None => (s, None),
+    };
+    let dt = time::PrimitiveDateTime::parse(prefix, NAIVE_DATETIME_PREFIX_FORMAT)
+        .map_err(|e| e.to_string())?;
+    let nanosecond: u32 = match frac {
+        Some(frac) => frac.parse().map_err(|_| "invalid fractional seconds".to_string())?,
+        None => 0,
+    };
+    dt.replace_nanosecond(nanosecond).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "time")]
+fn format_naive_datetime(dt: &time::PrimitiveDateTime) -> String {
+    // Mirrors chrono's `%.f`, which always zero-pads to 9 digits.
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+        dt.year(),
+        u8::from(dt.month()),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second(),
+        dt.nanosecond(),
+    )
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match chrono::DateTime::parse_from_rfc3339(&s) {
+            Ok(dt) => Ok(DateTime::WithTz(dt)),
+            Err(_) => match chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S.%f") {
+                Ok(dt) => Ok(DateTime::WithoutTz(dt)),
+                Err(_) => Err(serde::de::Error::custom("invalid date format")),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339) {
+            Ok(dt) => Ok(DateTime::WithTz(dt)),
+            Err(_) => {
+                parse_naive_datetime(&s).map(DateTime::WithoutTz).map_err(serde::de::Error::custom)
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl Serialize for DateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match self {
+            DateTime::WithTz(dt) => dt.to_rfc3339().serialize(serializer),
+            DateTime::WithoutTz(dt) => dt
+                .format("%Y-%m-%dT%H:%M:%S.%f")
+                .to_string()
+                .serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl Serialize for DateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match self {
+            DateTime::WithTz(dt) => dt
+                .format(&time::format_description::well_known::Rfc3339)
+                .map_err(serde::ser::Error::custom)?
+                .serialize(serializer),
+            DateTime::WithoutTz(dt) => format_naive_datetime(dt).serialize(serializer),
+        }
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl From<chrono::DateTime<chrono::FixedOffset>> for DateTime {
+    fn from(dt: chrono::DateTime<chrono::FixedOffset>) -> DateTime {
+        DateTime::WithTz(dt)
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl From<chrono::NaiveDateTime> for DateTime {
+    fn from(dt: chrono::NaiveDateTime) -> DateTime {
+        DateTime::WithoutTz(dt)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for DateTime {
+    fn from(dt: time::OffsetDateTime) -> DateTime {
+        DateTime::WithTz(dt)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for DateTime {
+    fn from(dt: time::PrimitiveDateTime) -> DateTime {
+        DateTime::WithoutTz(dt)
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl From<DateTime> for String {
+    fn from(dt: DateTime) -> String {
+        match dt {
+            DateTime::WithTz(dt) => dt.to_rfc3339(),
+            DateTime::WithoutTz(dt) => dt.format("%Y-%m-%dT%H:%M:%S.%f").to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<DateTime> for String {
+    fn from(dt: DateTime) -> String {
+        match dt {
+            DateTime::WithTz(dt) => dt
+                .format(&time::format_description::well_known::Rfc3339)
+                .expect("formatting an OffsetDateTime as RFC3339 should not fail"),
+            DateTime::WithoutTz(dt) => format_naive_datetime(&dt),
+        }
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DateTime::WithTz(dt) => write!(f, "{}", dt.to_rfc3339()),
+            DateTime::WithoutTz(dt) => write!(f, "{}", dt.format("%Y-%m-%dT%H:%M:%S.%f")),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(*self))
+    }
+}
+
+pub(crate) fn bool_from_bootable_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer)?.as_ref() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(de::Error::invalid_value(
+            de::Unexpected::Str(other),
+            &"true or false",
+        )),
+    }
+}
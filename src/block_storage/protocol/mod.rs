@@ -0,0 +1,31 @@
+// Copyright 2024 Sandro-Alessio Gierens <sandro@gierens.de>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON structures and protocol bits for the Block Storage API.
+
+#![allow(missing_docs)]
+
+mod datetime;
+mod volume;
+mod volume_create;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use datetime::DateTime;
+pub use datetime::Timestamp;
+
+pub use volume::{
+    Link, MigrationStatus, ReplicationStatus, Volume, VolumeAttachment, VolumeRoot,
+    VolumeSortKey, VolumeStatus, VolumesRoot,
+};
+pub use volume_create::{VolumeCreate, VolumeCreateRoot, VolumeSchedulerHints};
@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! JSON structures and protocol bits for the Block Storage API.
+//! Volume resource structures and enums.
 
-#![allow(missing_docs)]
-
-use serde::{de, Deserialize, Deserializer, Serialize};
+use serde::Deserialize;
 use std::collections::HashMap;
 
+use super::datetime::bool_from_bootable_string;
+use super::datetime::Timestamp;
+
 protocol_enum! {
     #[doc = "Possible volume statuses."]
     enum VolumeStatus {
@@ -55,77 +56,36 @@ protocol_enum! {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum DateTime {
-    WithTz(chrono::DateTime<chrono::FixedOffset>),
-    WithoutTz(chrono::NaiveDateTime),
-}
-
-impl<'de> Deserialize<'de> for DateTime {
-    fn deserialize<D>(deserializer: D) -> Result<DateTime, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        match chrono::DateTime::parse_from_rfc3339(&s) {
-            Ok(dt) => Ok(DateTime::WithTz(dt)),
-            Err(_) => match chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S.%f") {
-                Ok(dt) => Ok(DateTime::WithoutTz(dt)),
-                Err(_) => Err(serde::de::Error::custom("invalid date format")),
-            },
-        }
-    }
-}
-
-impl Serialize for DateTime {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        match self {
-            DateTime::WithTz(dt) => dt.to_rfc3339().serialize(serializer),
-            DateTime::WithoutTz(dt) => dt
-                .format("%Y-%m-%dT%H:%M:%S.%f")
-                .to_string()
-                .serialize(serializer),
-        }
-    }
-}
-
-impl From<chrono::DateTime<chrono::FixedOffset>> for DateTime {
-    fn from(dt: chrono::DateTime<chrono::FixedOffset>) -> DateTime {
-        DateTime::WithTz(dt)
-    }
-}
-
-impl From<chrono::NaiveDateTime> for DateTime {
-    fn from(dt: chrono::NaiveDateTime) -> DateTime {
-        DateTime::WithoutTz(dt)
-    }
-}
-
-impl From<DateTime> for String {
-    fn from(dt: DateTime) -> String {
-        match dt {
-            DateTime::WithTz(dt) => dt.to_rfc3339(),
-            DateTime::WithoutTz(dt) => dt.format("%Y-%m-%dT%H:%M:%S.%f").to_string(),
-        }
+// `protocol_enum!` can't attach `#[default]` to a variant, so this can't
+// be a derive.
+#[allow(clippy::derivable_impls)]
+impl Default for VolumeSortKey {
+    fn default() -> VolumeSortKey {
+        VolumeSortKey::CreatedAt
     }
 }
 
-impl std::fmt::Display for DateTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            DateTime::WithTz(dt) => write!(f, "{}", dt.to_rfc3339()),
-            DateTime::WithoutTz(dt) => write!(f, "{}", dt.format("%Y-%m-%dT%H:%M:%S.%f")),
-        }
+protocol_enum! {
+    #[doc = "Volume migration status."]
+    enum MigrationStatus {
+        Migrating = "migrating",
+        Error = "error",
+        Success = "success",
+        Starting = "starting",
+        Completing = "completing",
     }
+    other
 }
 
-impl Default for VolumeSortKey {
-    fn default() -> VolumeSortKey {
-        VolumeSortKey::CreatedAt
+protocol_enum! {
+    #[doc = "Volume replication status."]
+    enum ReplicationStatus {
+        Disabled = "disabled",
+        Enabled = "enabled",
+        Error = "error",
+        NotCapable = "not-capable",
     }
+    other
 }
 
 /// A volume attachment.
@@ -134,7 +94,7 @@ impl Default for VolumeSortKey {
 pub struct VolumeAttachment {
     pub server_id: String, // this should be a reference to a server
     pub attachment_id: String,
-    pub attached_at: String,
+    pub attached_at: Timestamp,
     pub host_name: Option<String>,
     pub volume_id: String, // this should be a reference to a volume
     pub device: String,
@@ -147,20 +107,6 @@ pub struct Link {
     pub href: String,
 }
 
-fn bool_from_bootable_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    match String::deserialize(deserializer)?.as_ref() {
-        "true" => Ok(true),
-        "false" => Ok(false),
-        other => Err(de::Error::invalid_value(
-            de::Unexpected::Str(other),
-            &"true or false",
-        )),
-    }
-}
-
 /// A volume.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Volume {
@@ -169,7 +115,7 @@ pub struct Volume {
     // Some fields are not actually optional, but don't work without Option<>.
     // Others should maybe be enums, but the possible values are not documented.
     // There are comments for these cases.
-    pub migration_status: Option<String>, // consider enum
+    pub migration_status: Option<MigrationStatus>,
     pub attachments: Vec<VolumeAttachment>,
     pub links: Vec<Link>,
     pub availability_zone: Option<String>,
@@ -177,8 +123,8 @@ pub struct Volume {
     pub host: Option<String>,
     pub encrypted: bool,
     pub encryption_key_id: Option<String>,
-    pub updated_at: Option<DateTime>,
-    pub replication_status: Option<String>, // not optional in spec, also consider enum
+    pub updated_at: Option<Timestamp>,
+    pub replication_status: Option<ReplicationStatus>, // not optional in spec
     pub snapshot_id: Option<String>,
     pub id: String,
     pub size: u64,
@@ -206,7 +152,7 @@ pub struct Volume {
     pub name: String,
     #[serde(deserialize_with = "bool_from_bootable_string")]
     pub bootable: bool,
-    pub created_at: DateTime,
+    pub created_at: Timestamp,
     pub volumes: Option<Vec<Volume>>, // not optional in spec
     pub volume_type: String,          // consider enum
     pub volume_type_id: Option<HashMap<String, String>>, // not optional in spec
@@ -232,56 +178,3 @@ pub struct VolumeRoot {
 pub struct VolumesRoot {
     pub volumes: Vec<Volume>,
 }
-
-/// Volume arguments for a create request.
-#[derive(Debug, Clone, Serialize)]
-pub struct VolumeCreate {
-    pub size: u64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_zone: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "source_volid")]
-    pub source_volume_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_id: Option<String>,
-    pub name: String, // not optional in spec, but doesn't work with None/null, only with ""
-    #[serde(skip_serializing_if = "Option::is_none", rename = "imageRef")]
-    pub image_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_type: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
-    #[serde(
-        skip_serializing_if = "Option::is_none",
-        rename = "consistency_group_id"
-    )]
-    pub consistency_group_id: Option<String>,
-}
-
-/// A volume create request.
-#[derive(Clone, Debug, Serialize)]
-pub struct VolumeCreateRoot {
-    pub volume: VolumeCreate,
-    // NOTE: this can also contain a scheduler_hints field
-}
-
-impl VolumeCreate {
-    pub fn new(size: u64) -> VolumeCreate {
-        VolumeCreate {
-            size,
-            availability_zone: None,
-            source_volume_id: None,
-            description: None,
-            snapshot_id: None,
-            backup_id: None,
-            name: "".to_string(),
-            image_id: None,
-            volume_type: None,
-            metadata: None,
-            consistency_group_id: None,
-        }
-    }
-}
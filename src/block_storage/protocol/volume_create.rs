@@ -0,0 +1,117 @@
+// Copyright 2024 Sandro-Alessio Gierens <sandro@gierens.de>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Volume create request structures.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Volume arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeCreate {
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "source_volid")]
+    pub source_volume_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_id: Option<String>,
+    pub name: String, // not optional in spec, but doesn't work with None/null, only with ""
+    #[serde(skip_serializing_if = "Option::is_none", rename = "imageRef")]
+    pub image_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "consistency_group_id"
+    )]
+    pub consistency_group_id: Option<String>,
+    // Serialized by `VolumeCreateRoot` as a sibling `os:scheduler_hints`
+    // key, not as part of the `volume` body, so it is not serialized here.
+    #[serde(skip)]
+    pub scheduler_hints: Option<VolumeSchedulerHints>,
+}
+
+/// Scheduler hints for volume creation, letting callers place a new volume
+/// relative to existing instances or volumes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VolumeSchedulerHints {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub same_host: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub different_host: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_to_instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// A volume create request.
+#[derive(Clone, Debug)]
+pub struct VolumeCreateRoot {
+    pub volume: VolumeCreate,
+}
+
+impl Serialize for VolumeCreateRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let len = if self.volume.scheduler_hints.is_some() {
+            2
+        } else {
+            1
+        };
+        let mut root = serializer.serialize_struct("VolumeCreateRoot", len)?;
+        root.serialize_field("volume", &self.volume)?;
+        if let Some(scheduler_hints) = &self.volume.scheduler_hints {
+            root.serialize_field("os:scheduler_hints", scheduler_hints)?;
+        }
+        root.end()
+    }
+}
+
+impl VolumeCreate {
+    pub fn new(size: u64) -> VolumeCreate {
+        VolumeCreate {
+            size,
+            availability_zone: None,
+            source_volume_id: None,
+            description: None,
+            snapshot_id: None,
+            backup_id: None,
+            name: "".to_string(),
+            image_id: None,
+            volume_type: None,
+            metadata: None,
+            consistency_group_id: None,
+            scheduler_hints: None,
+        }
+    }
+
+    pub fn with_scheduler_hints(mut self, scheduler_hints: VolumeSchedulerHints) -> VolumeCreate {
+        self.scheduler_hints = Some(scheduler_hints);
+        self
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2024 Sandro-Alessio Gierens <sandro@gierens.de>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helper macros shared across protocol modules.
+
+macro_rules! protocol_enum {
+    (#[doc = $doc:expr] enum $name:ident { $($item:ident = $s:expr),+ $(,)* }) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum $name {
+            $(
+                #[allow(missing_docs)]
+                $item,
+            )+
+        }
+
+        impl $name {
+            fn as_ref(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$item => $s,
+                    )+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self.as_ref())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<$name, String> {
+                match s {
+                    $(
+                        $s => Ok($name::$item),
+                    )+
+                    _ => Err(format!("unknown value {}", s)),
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_ref())
+            }
+        }
+    };
+
+    // Like the arm above, but for enums that need to tolerate values not in
+    // the documented list: unrecognized wire strings become `Other(String)`
+    // instead of a deserialization error. The trailing `other` marker (rather
+    // than a variant-like `Other(String)` entry in the list itself) avoids an
+    // ambiguity in how `macro_rules!` matches the item repetition.
+    (#[doc = $doc:expr] enum $name:ident { $($item:ident = $s:expr),+ $(,)* } other) => {
+        #[doc = $doc]
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum $name {
+            $(
+                #[allow(missing_docs)]
+                $item,
+            )+
+            #[allow(missing_docs)]
+            Other(String),
+        }
+
+        impl $name {
+            fn as_ref(&self) -> &str {
+                match self {
+                    $(
+                        $name::$item => $s,
+                    )+
+                    $name::Other(s) => s,
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self.as_ref())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(match s.as_str() {
+                    $(
+                        $s => $name::$item,
+                    )+
+                    _ => $name::Other(s),
+                })
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_ref())
+            }
+        }
+    };
+}